@@ -0,0 +1,134 @@
+//! Pedersen-style verifiable distributed key generation (DKG): a set of participants
+//! jointly derive a `(threshold, participants)` BLS keypair with no trusted dealer,
+//! each verifying the shares they receive against the dealer's published commitments.
+
+use super::amcl_utils::{Big, GroupG1};
+use super::keys::{PublicKey, SecretKey};
+use super::scalar;
+use super::threshold::evaluate_polynomial;
+
+/// Check that `share`, claimed to be the evaluation at `j` of a dealer's secret
+/// polynomial, is consistent with that dealer's published coefficient `commitment`:
+/// `GENERATORG1 * share == sum_k commitment[k] * j^k`.
+///
+/// A dealer who sends a participant a share that does not match their own published
+/// commitment is caught here, before it is folded into the participant's final key.
+pub fn verify_share(share: &SecretKey, j: u32, commitment: &[PublicKey]) -> bool {
+    let lhs = PublicKey::from_secret_key(share);
+
+    let j_scalar = scalar::reduce(&j.to_be_bytes());
+    let mut power = Big::new_int(1);
+    let mut acc: Option<GroupG1> = None;
+    for c in commitment {
+        let term = c.point.as_raw().mul(&power);
+        acc = Some(match acc {
+            None => term,
+            Some(sum) => term.add(&sum),
+        });
+        power = scalar::mul(&power, &j_scalar);
+    }
+    let rhs = match acc {
+        Some(point) => PublicKey::new_from_raw(&point),
+        None => PublicKey::new_from_raw(&GroupG1::new()),
+    };
+
+    lhs == rhs
+}
+
+/// Fold the shares a participant received from every dealer (after each has been
+/// checked with `verify_share`) into that participant's final secret key share, and
+/// sum every dealer's constant-term commitment into the joint group public key.
+pub fn aggregate_dkg(received_shares: &[SecretKey], all_commitments: &[Vec<PublicKey>]) -> (SecretKey, PublicKey) {
+    let mut secret = Big::new();
+    for share in received_shares {
+        secret = scalar::add(&secret, share.as_raw());
+    }
+
+    let mut acc: Option<GroupG1> = None;
+    for commitment in all_commitments {
+        let constant_term = commitment[0].point.as_raw().clone();
+        acc = Some(match acc {
+            None => constant_term,
+            Some(sum) => constant_term.add(&sum),
+        });
+    }
+    let group_pk = match acc {
+        Some(point) => PublicKey::new_from_raw(&point),
+        None => PublicKey::new_from_raw(&GroupG1::new()),
+    };
+
+    (SecretKey::from_raw(secret), group_pk)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rand;
+
+    use self::rand::Rng;
+    use super::*;
+
+    /// Test-only stand-in for a single dealer: samples a degree-`threshold - 1`
+    /// polynomial, publishes its coefficient commitments, and evaluates it at
+    /// `1..=participants`.
+    fn deal<R: Rng + ?Sized>(
+        threshold: usize,
+        participants: usize,
+        rng: &mut R,
+    ) -> (Vec<PublicKey>, Vec<SecretKey>) {
+        let coefficients: Vec<Big> = (0..threshold)
+            .map(|_| SecretKey::random(rng).as_raw().clone())
+            .collect();
+
+        let commitment: Vec<PublicKey> = coefficients
+            .iter()
+            .map(|c| PublicKey::from_secret_key(&SecretKey::from_raw(c.clone())))
+            .collect();
+
+        let shares: Vec<SecretKey> = (1..=participants as u32)
+            .map(|x| SecretKey::from_raw(evaluate_polynomial(&coefficients, x)))
+            .collect();
+
+        (commitment, shares)
+    }
+
+    #[test]
+    fn test_dkg_happy_path_verifies_and_aggregates_to_a_consistent_group_key() {
+        let mut rng = rand::thread_rng();
+        let threshold = 3;
+        let participants = 5;
+        let dealers = 4;
+
+        let mut deals = Vec::with_capacity(dealers);
+        for _ in 0..dealers {
+            deals.push(deal(threshold, participants, &mut rng));
+        }
+        let commitments: Vec<Vec<PublicKey>> = deals.iter().map(|(c, _)| c.clone()).collect();
+
+        // Every participant verifies and aggregates their share from every dealer.
+        let mut group_pk = None;
+        for participant in 1..=participants as u32 {
+            let mut received = Vec::with_capacity(dealers);
+            for (commitment, shares) in &deals {
+                let share = &shares[(participant - 1) as usize];
+                assert!(verify_share(share, participant, commitment));
+                received.push(share.clone());
+            }
+
+            let (_own_share, pk) = aggregate_dkg(&received, &commitments);
+            match &group_pk {
+                None => group_pk = Some(pk),
+                Some(expected) => assert_eq!(&pk, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dkg_rejects_a_tampered_share() {
+        let mut rng = rand::thread_rng();
+        let (commitment, shares) = deal(3, 5, &mut rng);
+
+        let tampered = SecretKey::random(&mut rng);
+        assert!(!verify_share(&tampered, 1, &commitment));
+        assert!(verify_share(&shares[0], 1, &commitment));
+    }
+}
@@ -0,0 +1,31 @@
+extern crate hkdf;
+extern crate sha2;
+
+use self::hkdf::Hkdf;
+use self::sha2::{Digest, Sha256};
+
+/// `HKDF-Extract` from RFC 5869, instantiated with SHA-256.
+pub(crate) fn extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    let (prk, _) = Hkdf::<Sha256>::extract(Some(salt), ikm);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&prk);
+    out
+}
+
+/// `HKDF-Expand` from RFC 5869, instantiated with SHA-256.
+pub(crate) fn expand(prk: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    let hk = Hkdf::<Sha256>::from_prk(prk).expect("a 32-byte PRK is always valid for HKDF-SHA256");
+    let mut okm = vec![0u8; length];
+    hk.expand(info, &mut okm)
+        .expect("requested length is within the HKDF-SHA256 output limit");
+    okm
+}
+
+/// Plain SHA-256, used by the EIP-2333 Lamport key construction.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&hasher.finalize());
+    out
+}
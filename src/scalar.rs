@@ -0,0 +1,61 @@
+//! Scalar (mod `CURVE_ORDER`) arithmetic shared by key-splitting, recovery and DKG.
+//!
+//! `mul`/`inverse` are thin wrappers over `amcl`'s own `Big`/`DBig` modular arithmetic
+//! rather than hand-rolled routines, so this module doesn't grow a second, unaudited
+//! implementation of the field.
+
+use super::amcl_utils::{Big, DBig, CURVE_ORDER, MODBYTES};
+
+/// The group order of the pairing-friendly curve, as a `Big`.
+pub(crate) fn order() -> Big {
+    Big::new_ints(&CURVE_ORDER)
+}
+
+/// Interpret `bytes` as a big-endian integer and reduce it modulo `CURVE_ORDER`.
+pub(crate) fn reduce(bytes: &[u8]) -> Big {
+    let mut padded = vec![0u8; MODBYTES - bytes.len()];
+    padded.extend_from_slice(bytes);
+
+    let mut x = Big::frombytes(&padded);
+    x.rmod(&order());
+    x
+}
+
+/// `(a + b) mod CURVE_ORDER`.
+pub(crate) fn add(a: &Big, b: &Big) -> Big {
+    let order = order();
+    let mut sum = a.clone();
+    sum.add(b);
+    while sum >= order {
+        sum.sub(&order);
+    }
+    sum
+}
+
+/// `(a - b) mod CURVE_ORDER`.
+pub(crate) fn sub(a: &Big, b: &Big) -> Big {
+    let order = order();
+    if *a >= *b {
+        let mut diff = a.clone();
+        diff.sub(b);
+        diff
+    } else {
+        let mut diff = order.clone();
+        diff.sub(b);
+        diff.add(a);
+        diff
+    }
+}
+
+/// `(a * b) mod CURVE_ORDER`, via `amcl`'s double-length multiply and reduce.
+pub(crate) fn mul(a: &Big, b: &Big) -> Big {
+    let mut product: DBig = a.mul(b);
+    product.dmod(&order())
+}
+
+/// The multiplicative inverse of `a` modulo the (prime) `CURVE_ORDER`.
+pub(crate) fn inverse(a: &Big) -> Big {
+    let mut inv = a.clone();
+    inv.invmodp(&order());
+    inv
+}
@@ -0,0 +1,98 @@
+//! `serde` support for `SecretKey` and `PublicKey`, gated behind the `serde` feature so
+//! consumers who don't need it don't pay for the dependency.
+//!
+//! Non-human-readable formats (bincode and friends) serialize the compressed byte
+//! encoding directly; human-readable formats (JSON and friends) use lowercase hex.
+//! `Deserialize` always routes through `from_bytes`, so the existing range and
+//! point-on-curve checks still run on every deserialized key.
+
+extern crate hex;
+extern crate serde;
+
+use self::serde::de::Error as DeError;
+use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::keys::{PublicKey, SecretKey};
+
+impl Serialize for SecretKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(self.as_bytes()))
+        } else {
+            serializer.serialize_bytes(&self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            hex::decode(s).map_err(DeError::custom)?
+        } else {
+            Vec::<u8>::deserialize(deserializer)?
+        };
+        SecretKey::from_bytes(&bytes).map_err(|e| DeError::custom(format!("{:?}", e)))
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(self.as_bytes()))
+        } else {
+            serializer.serialize_bytes(&self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            hex::decode(s).map_err(DeError::custom)?
+        } else {
+            Vec::<u8>::deserialize(deserializer)?
+        };
+        PublicKey::from_bytes(&bytes).map_err(|e| DeError::custom(format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate bincode;
+    extern crate rand;
+    extern crate serde_json;
+
+    use super::super::keys::Keypair;
+    use super::*;
+
+    #[test]
+    fn test_secret_key_bincode_round_trip() {
+        let sk = SecretKey::random(&mut rand::thread_rng());
+        let encoded = bincode::serialize(&sk).unwrap();
+        let decoded: SecretKey = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(sk, decoded);
+    }
+
+    #[test]
+    fn test_secret_key_json_round_trip() {
+        let sk = SecretKey::random(&mut rand::thread_rng());
+        let encoded = serde_json::to_string(&sk).unwrap();
+        assert_eq!(encoded, format!("\"{}\"", hex::encode(sk.as_bytes())));
+        let decoded: SecretKey = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(sk, decoded);
+    }
+
+    #[test]
+    fn test_keypair_bincode_and_json_round_trip() {
+        let keypair = Keypair::random(&mut rand::thread_rng());
+
+        let encoded = bincode::serialize(&keypair).unwrap();
+        let decoded: Keypair = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(keypair, decoded);
+
+        let encoded = serde_json::to_string(&keypair).unwrap();
+        let decoded: Keypair = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(keypair, decoded);
+    }
+}
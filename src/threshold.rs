@@ -0,0 +1,185 @@
+//! `(threshold, shares)` secret sharing and threshold BLS signing on top of the
+//! existing [`SecretKey`]/[`PublicKey`]/[`Signature`] types.
+
+extern crate rand;
+
+use super::amcl_utils::Big;
+use super::errors::DecodeError;
+use super::g2::G2Point;
+use super::keys::SecretKey;
+use super::scalar;
+use super::signature::Signature;
+use rand::Rng;
+
+/// A single participant's share of a `(threshold, shares)` Shamir-split `SecretKey`,
+/// identified by the 1-based evaluation point at which the sharing polynomial was
+/// sampled to produce it.
+pub type KeyShare = (u32, SecretKey);
+
+/// A single participant's partial signature over a `(threshold, shares)` split key,
+/// identified by the same 1-based evaluation point as its `KeyShare`.
+pub type SignatureShare = (u32, Signature);
+
+impl SecretKey {
+    /// Split `self` into `shares` secret-key shares such that any `threshold` of them
+    /// reconstruct it via [`SecretKey::recover`], while fewer than `threshold` reveal
+    /// nothing about it.
+    ///
+    /// Samples a degree-`threshold - 1` polynomial over the scalar field whose constant
+    /// term is `self`, then evaluates it at `1..=shares` (point `0` is never handed out,
+    /// since it would reveal the secret directly).
+    pub fn split<R: Rng + ?Sized>(
+        &self,
+        threshold: usize,
+        shares: usize,
+        rng: &mut R,
+    ) -> Vec<KeyShare> {
+        let coefficients: Vec<Big> = (0..threshold)
+            .map(|i| {
+                if i == 0 {
+                    self.as_raw().clone()
+                } else {
+                    SecretKey::random(rng).as_raw().clone()
+                }
+            })
+            .collect();
+
+        (1..=shares as u32)
+            .map(|x| (x, SecretKey::from_raw(evaluate_polynomial(&coefficients, x))))
+            .collect()
+    }
+
+    /// Reconstruct a `SecretKey` from `threshold` or more shares produced by `split`,
+    /// via Lagrange interpolation of the sharing polynomial at `x = 0`.
+    pub fn recover(shares: &[KeyShare]) -> Result<SecretKey, DecodeError> {
+        if shares.is_empty() {
+            return Err(DecodeError::IncorrectSize);
+        }
+
+        let xs: Vec<u32> = shares.iter().map(|(x, _)| *x).collect();
+        let mut secret = Big::new();
+        for (i, (_, share_i)) in shares.iter().enumerate() {
+            let lambda = lagrange_coefficient(&xs, i);
+            secret = scalar::add(&secret, &scalar::mul(&lambda, share_i.as_raw()));
+        }
+
+        Ok(SecretKey::from_raw(secret))
+    }
+}
+
+impl Signature {
+    /// Combine `threshold`-or-more partial signatures produced against the shares of a
+    /// `SecretKey::split` key into the full signature the unsplit key would have
+    /// produced, by applying the same Lagrange coefficients `SecretKey::recover` uses,
+    /// as scalar multiplications on the G2 signature points.
+    pub fn aggregate_threshold(partials: &[SignatureShare]) -> Result<Signature, DecodeError> {
+        if partials.is_empty() {
+            return Err(DecodeError::IncorrectSize);
+        }
+
+        let xs: Vec<u32> = partials.iter().map(|(x, _)| *x).collect();
+        let mut acc = None;
+        for (i, (_, sig_i)) in partials.iter().enumerate() {
+            let lambda = lagrange_coefficient(&xs, i);
+            let scaled = sig_i.point.as_raw().mul(&lambda);
+            acc = Some(match acc {
+                None => scaled,
+                Some(sum) => scaled.add(&sum),
+            });
+        }
+
+        Ok(Signature {
+            point: G2Point::from_raw(acc.expect("partials is non-empty, so acc was set")),
+        })
+    }
+}
+
+/// Evaluate the polynomial with the given `coefficients` (lowest degree first) at `x`,
+/// modulo `CURVE_ORDER`, via Horner's method.
+pub(crate) fn evaluate_polynomial(coefficients: &[Big], x: u32) -> Big {
+    let x = scalar::reduce(&x.to_be_bytes());
+    let mut result = Big::new();
+    for coefficient in coefficients.iter().rev() {
+        result = scalar::add(&scalar::mul(&result, &x), coefficient);
+    }
+    result
+}
+
+/// The Lagrange coefficient `lambda_i` for interpolating at `0` the polynomial passing
+/// through the 1-based evaluation points `xs`, for the share taken at `xs[i]`:
+/// `lambda_i = prod_{j != i} x_j / (x_j - x_i) mod CURVE_ORDER`.
+fn lagrange_coefficient(xs: &[u32], i: usize) -> Big {
+    let xi = scalar::reduce(&xs[i].to_be_bytes());
+    let mut numerator = Big::new_int(1);
+    let mut denominator = Big::new_int(1);
+    for (j, &xj) in xs.iter().enumerate() {
+        if j == i {
+            continue;
+        }
+        let xj = scalar::reduce(&xj.to_be_bytes());
+        numerator = scalar::mul(&numerator, &xj);
+        denominator = scalar::mul(&denominator, &scalar::sub(&xj, &xi));
+    }
+    scalar::mul(&numerator, &scalar::inverse(&denominator))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rand;
+
+    use super::super::keys::PublicKey;
+    use super::super::signature::Signature;
+    use super::*;
+
+    #[test]
+    fn test_threshold_signing_with_exactly_threshold_shares_verifies() {
+        let mut rng = rand::thread_rng();
+        let sk = SecretKey::random(&mut rng);
+        let pk = PublicKey::from_secret_key(&sk);
+        let message = "cats".as_bytes();
+
+        let shares = sk.split(3, 5, &mut rng);
+        let partials: Vec<SignatureShare> = shares[..3]
+            .iter()
+            .map(|(x, share)| (*x, Signature::new(&message, share)))
+            .collect();
+
+        let signature = Signature::aggregate_threshold(&partials).unwrap();
+        assert!(signature.verify(&message, &pk));
+    }
+
+    #[test]
+    fn test_threshold_signing_below_threshold_does_not_verify() {
+        let mut rng = rand::thread_rng();
+        let sk = SecretKey::random(&mut rng);
+        let pk = PublicKey::from_secret_key(&sk);
+        let message = "cats".as_bytes();
+
+        let shares = sk.split(3, 5, &mut rng);
+        let partials: Vec<SignatureShare> = shares[..2]
+            .iter()
+            .map(|(x, share)| (*x, Signature::new(&message, share)))
+            .collect();
+
+        let signature = Signature::aggregate_threshold(&partials).unwrap();
+        assert!(!signature.verify(&message, &pk));
+    }
+
+    #[test]
+    fn test_secret_key_split_and_recover_round_trips() {
+        let mut rng = rand::thread_rng();
+        let sk = SecretKey::random(&mut rng);
+
+        let shares = sk.split(3, 5, &mut rng);
+        let recovered = SecretKey::recover(&shares[1..4]).unwrap();
+        assert_eq!(sk, recovered);
+    }
+
+    #[test]
+    fn test_secret_key_recover_rejects_empty_shares() {
+        assert_eq!(
+            SecretKey::recover(&[]),
+            Err(DecodeError::IncorrectSize)
+        );
+    }
+}